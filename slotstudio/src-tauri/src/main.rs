@@ -1,11 +1,19 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use std::{
+    collections::HashMap,
     ffi::OsStr,
     fs,
-    path::{Path, PathBuf},
+    io::{Read, Write},
+    path::{Component, Path, PathBuf},
+    sync::Mutex,
 };
 
+use sha2::{Digest, Sha256};
+use tempfile::TempDir;
+
+const IMPORTS_MANIFEST_FILE: &str = "manifest.json";
+
 #[tauri::command]
 fn read_project_dir(path: String) -> Result<Vec<String>, String> {
     fs::read_dir(path)
@@ -20,6 +28,114 @@ fn read_project_dir(path: String) -> Result<Vec<String>, String> {
         .collect()
 }
 
+const DEFAULT_TREE_MAX_DEPTH: usize = 8;
+
+#[derive(serde::Serialize)]
+struct TreeNode {
+    path: String,
+    is_dir: bool,
+    size: u64,
+    extension: Option<String>,
+    children: Vec<TreeNode>,
+}
+
+fn should_skip_tree_entry(name: &str, is_dir: bool) -> bool {
+    name.starts_with('.') || (is_dir && (name == "node_modules" || name == "target"))
+}
+
+fn build_project_tree(
+    dir: &Path,
+    rel_prefix: &str,
+    depth: usize,
+    max_depth: usize,
+    extensions: &Option<Vec<String>>,
+) -> Result<Vec<TreeNode>, String> {
+    let mut nodes = Vec::new();
+
+    for entry in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let file_type = entry.file_type().map_err(|e| e.to_string())?;
+        let name = entry
+            .file_name()
+            .into_string()
+            .map_err(|_| String::from("Invalid UTF-8 in file name"))?;
+
+        if should_skip_tree_entry(&name, file_type.is_dir()) {
+            continue;
+        }
+
+        let rel_path = if rel_prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}/{}", rel_prefix, name)
+        };
+
+        if file_type.is_dir() {
+            let children = if depth < max_depth {
+                build_project_tree(&entry.path(), &rel_path, depth + 1, max_depth, extensions)?
+            } else {
+                Vec::new()
+            };
+            nodes.push(TreeNode {
+                path: rel_path,
+                is_dir: true,
+                size: 0,
+                extension: None,
+                children,
+            });
+            continue;
+        }
+
+        let extension = Path::new(&name)
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(|ext| ext.to_lowercase());
+
+        if let Some(allowed) = extensions {
+            let matches = extension
+                .as_deref()
+                .is_some_and(|ext| allowed.iter().any(|a| a == ext));
+            if !matches {
+                continue;
+            }
+        }
+
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        nodes.push(TreeNode {
+            path: rel_path,
+            is_dir: false,
+            size,
+            extension,
+            children: Vec::new(),
+        });
+    }
+
+    nodes.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(nodes)
+}
+
+#[tauri::command]
+fn read_project_dir_tree(
+    path: String,
+    max_depth: Option<usize>,
+    extensions: Option<Vec<String>>,
+) -> Result<Vec<TreeNode>, String> {
+    let root = PathBuf::from(&path);
+    let normalized_extensions = extensions.map(|exts| {
+        exts.into_iter()
+            .map(|ext| ext.trim_start_matches('.').to_lowercase())
+            .collect()
+    });
+
+    build_project_tree(
+        &root,
+        "",
+        0,
+        max_depth.unwrap_or(DEFAULT_TREE_MAX_DEPTH),
+        &normalized_extensions,
+    )
+}
+
 fn unique_destination(mut dest: PathBuf) -> PathBuf {
     if !dest.exists() {
         return dest;
@@ -39,8 +155,7 @@ fn unique_destination(mut dest: PathBuf) -> PathBuf {
 
     let mut counter = 1;
     loop {
-        let candidate =
-            parent.join(format!("{}-{}{}", file_stem, counter, extension));
+        let candidate = parent.join(format!("{}-{}{}", file_stem, counter, extension));
         if !candidate.exists() {
             return candidate;
         }
@@ -48,8 +163,162 @@ fn unique_destination(mut dest: PathBuf) -> PathBuf {
     }
 }
 
+/// Moves `source` to `destination`, falling back to copy-then-remove when
+/// they're on different filesystems (where `rename` can't work).
+fn move_file(source: &Path, destination: &Path) -> Result<(), String> {
+    if fs::rename(source, destination).is_ok() {
+        return Ok(());
+    }
+
+    fs::copy(source, destination).map_err(|e| format!("Failed to copy asset: {}", e))?;
+    fs::remove_file(source).map_err(|e| e.to_string())
+}
+
+fn digest_file(path: &Path) -> Result<String, String> {
+    let mut file = fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 8192];
+    loop {
+        let read = file.read(&mut buffer).map_err(|e| e.to_string())?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct ImportManifestEntry {
+    /// `None` once `purge_uncompressed_originals` has removed the
+    /// uncompressed copy; `compressed_path` becomes authoritative then.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    original_size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compressed_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compressed_size: Option<u64>,
+}
+
+type ImportsManifest = HashMap<String, ImportManifestEntry>;
+
+/// Resolves a manifest entry to the paths actually present on disk under
+/// `project_root`, preferring the uncompressed original. Manifest entries
+/// go stale the moment something removes the file out from under them
+/// (`purge_uncompressed_originals`, or a user manually cleaning
+/// `static/imports`), so every cache-hit consumer must verify before
+/// trusting one, rather than handing back a path to a file that's gone.
+fn resolve_cached_import(
+    project_root: &Path,
+    entry: &ImportManifestEntry,
+) -> Option<(String, Option<String>)> {
+    if let Some(path) = &entry.path {
+        if project_root.join(path).exists() {
+            let compressed = entry
+                .compressed_path
+                .as_ref()
+                .filter(|compressed_path| project_root.join(compressed_path).exists())
+                .cloned();
+            return Some((path.clone(), compressed));
+        }
+    }
+
+    if let Some(compressed_path) = &entry.compressed_path {
+        if project_root.join(compressed_path).exists() {
+            return Some((compressed_path.clone(), None));
+        }
+    }
+
+    None
+}
+
+fn load_imports_manifest(imports_dir: &Path) -> ImportsManifest {
+    fs::read_to_string(imports_dir.join(IMPORTS_MANIFEST_FILE))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_imports_manifest(imports_dir: &Path, manifest: &ImportsManifest) -> Result<(), String> {
+    let contents = serde_json::to_string_pretty(manifest).map_err(|e| e.to_string())?;
+    fs::write(imports_dir.join(IMPORTS_MANIFEST_FILE), contents).map_err(|e| e.to_string())
+}
+
+/// Brotli-compresses `source` into a `.br` sibling file at the given quality
+/// (0-11) and returns its path and size.
+fn compress_file(source: &Path, quality: u8) -> Result<(PathBuf, u64), String> {
+    let destination = PathBuf::from(format!("{}.br", source.to_string_lossy()));
+    let input = fs::read(source).map_err(|e| e.to_string())?;
+
+    let output_file = fs::File::create(&destination).map_err(|e| e.to_string())?;
+    let mut writer = brotli::CompressorWriter::new(output_file, 4096, quality as u32, 22);
+    writer.write_all(&input).map_err(|e| e.to_string())?;
+    writer.flush().map_err(|e| e.to_string())?;
+    drop(writer);
+
+    let compressed_size = fs::metadata(&destination).map_err(|e| e.to_string())?.len();
+    Ok((destination, compressed_size))
+}
+
+#[derive(serde::Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum ImportErrorKind {
+    NotFound,
+    CopyFailed,
+    InvalidName,
+}
+
+#[derive(serde::Serialize)]
+struct ImportOutcome {
+    source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    relative_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compressed_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_kind: Option<ImportErrorKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl ImportOutcome {
+    fn ok(source: String, relative_path: String) -> Self {
+        Self {
+            source,
+            relative_path: Some(relative_path),
+            compressed_path: None,
+            error_kind: None,
+            error: None,
+        }
+    }
+
+    fn ok_with_compressed(source: String, relative_path: String, compressed_path: String) -> Self {
+        Self {
+            source,
+            relative_path: Some(relative_path),
+            compressed_path: Some(compressed_path),
+            error_kind: None,
+            error: None,
+        }
+    }
+
+    fn err(source: String, kind: ImportErrorKind, message: String) -> Self {
+        Self {
+            source,
+            relative_path: None,
+            compressed_path: None,
+            error_kind: Some(kind),
+            error: Some(message),
+        }
+    }
+}
+
 #[tauri::command]
-fn import_assets(paths: Vec<String>) -> Result<Vec<String>, String> {
+fn import_assets(
+    paths: Vec<String>,
+    compress_quality: Option<u8>,
+) -> Result<Vec<ImportOutcome>, String> {
     if paths.is_empty() {
         return Ok(Vec::new());
     }
@@ -58,37 +327,485 @@ fn import_assets(paths: Vec<String>) -> Result<Vec<String>, String> {
     let static_dir = project_root.join("static").join("imports");
     fs::create_dir_all(&static_dir).map_err(|e| e.to_string())?;
 
-    let mut imported = Vec::new();
+    let mut manifest = load_imports_manifest(&static_dir);
+    let mut manifest_dirty = false;
+    let mut outcomes = Vec::new();
 
     for source_str in paths {
         let source_path = PathBuf::from(&source_str);
         if !source_path.exists() {
-            return Err(format!("Asset not found: {}", source_str));
+            outcomes.push(ImportOutcome::err(
+                source_str.clone(),
+                ImportErrorKind::NotFound,
+                format!("Asset not found: {}", source_str),
+            ));
+            continue;
         }
 
-        let file_name = source_path
-            .file_name()
-            .ok_or_else(|| format!("Invalid asset path: {}", source_str))?;
+        let file_name = match source_path.file_name() {
+            Some(name) => name,
+            None => {
+                outcomes.push(ImportOutcome::err(
+                    source_str.clone(),
+                    ImportErrorKind::InvalidName,
+                    format!("Invalid asset path: {}", source_str),
+                ));
+                continue;
+            }
+        };
+
+        let digest = match digest_file(&source_path) {
+            Ok(digest) => digest,
+            Err(error) => {
+                outcomes.push(ImportOutcome::err(
+                    source_str.clone(),
+                    ImportErrorKind::CopyFailed,
+                    error,
+                ));
+                continue;
+            }
+        };
+
+        if let Some(existing) = manifest.get(&digest) {
+            if let Some((served, compressed)) = resolve_cached_import(&project_root, existing) {
+                match compressed {
+                    Some(compressed_path) => outcomes.push(ImportOutcome::ok_with_compressed(
+                        source_str,
+                        served,
+                        compressed_path,
+                    )),
+                    None => outcomes.push(ImportOutcome::ok(source_str, served)),
+                }
+                continue;
+            }
+            // Cached entry is stale (its file(s) are gone) — fall through
+            // and re-copy from source instead of reporting success for a
+            // path that no longer exists.
+        }
 
         let destination = unique_destination(static_dir.join(file_name));
-        fs::copy(&source_path, &destination)
-            .map_err(|e| format!("Failed to copy asset: {}", e))?;
+        if let Err(error) = fs::copy(&source_path, &destination) {
+            outcomes.push(ImportOutcome::err(
+                source_str,
+                ImportErrorKind::CopyFailed,
+                format!("Failed to copy asset: {}", error),
+            ));
+            continue;
+        }
 
-        if let Ok(relative) = destination.strip_prefix(&project_root) {
-            imported.push(relative.to_string_lossy().to_string());
-        } else {
-            imported.push(destination.to_string_lossy().to_string());
+        let relative = destination
+            .strip_prefix(&project_root)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| destination.to_string_lossy().to_string());
+        let original_size = fs::metadata(&destination).map(|m| m.len()).unwrap_or(0);
+
+        let compressed = compress_quality.and_then(|quality| {
+            compress_file(&destination, quality).ok().map(
+                |(compressed_destination, compressed_size)| {
+                    let compressed_relative = compressed_destination
+                        .strip_prefix(&project_root)
+                        .map(|p| p.to_string_lossy().to_string())
+                        .unwrap_or_else(|_| compressed_destination.to_string_lossy().to_string());
+                    (compressed_relative, compressed_size)
+                },
+            )
+        });
+
+        manifest.insert(
+            digest,
+            ImportManifestEntry {
+                path: Some(relative.clone()),
+                original_size,
+                compressed_path: compressed.as_ref().map(|(path, _)| path.clone()),
+                compressed_size: compressed.as_ref().map(|(_, size)| *size),
+            },
+        );
+        manifest_dirty = true;
+
+        match compressed {
+            Some((compressed_relative, _)) => outcomes.push(ImportOutcome::ok_with_compressed(
+                source_str,
+                relative,
+                compressed_relative,
+            )),
+            None => outcomes.push(ImportOutcome::ok(source_str, relative)),
+        }
+    }
+
+    if manifest_dirty {
+        save_imports_manifest(&static_dir, &manifest)?;
+    }
+
+    Ok(outcomes)
+}
+
+/// Resolves `target_subpath` under `static_root`, rejecting anything that
+/// would escape it (`..`, absolute paths, Windows drive prefixes) once the
+/// path is normalized component-by-component.
+fn resolve_under_static(static_root: &Path, target_subpath: &str) -> Result<PathBuf, String> {
+    let mut resolved = PathBuf::new();
+    for component in Path::new(target_subpath).components() {
+        match component {
+            Component::Normal(part) => resolved.push(part),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                return Err(format!("Target path escapes static/: {}", target_subpath))
+            }
+            Component::RootDir | Component::Prefix(_) => {
+                return Err(format!(
+                    "Target path must be relative to static/: {}",
+                    target_subpath
+                ))
+            }
+        }
+    }
+
+    if resolved.as_os_str().is_empty() {
+        return Err(format!("Empty target path: {}", target_subpath));
+    }
+
+    Ok(static_root.join(resolved))
+}
+
+#[tauri::command]
+fn import_assets_to(targets: HashMap<String, String>) -> Result<Vec<ImportOutcome>, String> {
+    if targets.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let project_root = std::env::current_dir().map_err(|e| e.to_string())?;
+    let static_root = project_root.join("static");
+
+    let mut outcomes = Vec::new();
+
+    for (source_str, target_subpath) in targets {
+        let source_path = PathBuf::from(&source_str);
+        if !source_path.exists() {
+            outcomes.push(ImportOutcome::err(
+                source_str.clone(),
+                ImportErrorKind::NotFound,
+                format!("Asset not found: {}", source_str),
+            ));
+            continue;
+        }
+
+        let target_path = match resolve_under_static(&static_root, &target_subpath) {
+            Ok(path) => path,
+            Err(error) => {
+                outcomes.push(ImportOutcome::err(
+                    source_str,
+                    ImportErrorKind::InvalidName,
+                    error,
+                ));
+                continue;
+            }
+        };
+
+        let target_dir = match target_path.parent() {
+            Some(dir) => dir,
+            None => {
+                outcomes.push(ImportOutcome::err(
+                    source_str,
+                    ImportErrorKind::InvalidName,
+                    format!("Invalid target path: {}", target_subpath),
+                ));
+                continue;
+            }
+        };
+
+        if let Err(error) = fs::create_dir_all(target_dir) {
+            outcomes.push(ImportOutcome::err(
+                source_str,
+                ImportErrorKind::CopyFailed,
+                format!("Failed to create target directory: {}", error),
+            ));
+            continue;
+        }
+
+        let destination = unique_destination(target_path);
+        if let Err(error) = fs::copy(&source_path, &destination) {
+            outcomes.push(ImportOutcome::err(
+                source_str,
+                ImportErrorKind::CopyFailed,
+                format!("Failed to copy asset: {}", error),
+            ));
+            continue;
+        }
+
+        let relative = destination
+            .strip_prefix(&project_root)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| destination.to_string_lossy().to_string());
+
+        outcomes.push(ImportOutcome::ok(source_str, relative));
+    }
+
+    Ok(outcomes)
+}
+
+/// Creates (if needed) and returns `<project>/static/tmp`, the directory
+/// under which scratch preview imports are served.
+fn static_tmp_root() -> Result<PathBuf, String> {
+    let project_root = std::env::current_dir().map_err(|e| e.to_string())?;
+    let tmp_root = project_root.join("static").join("tmp");
+    fs::create_dir_all(&tmp_root).map_err(|e| e.to_string())?;
+    Ok(tmp_root)
+}
+
+fn new_scratch_dir() -> Result<TempDir, String> {
+    tempfile::Builder::new()
+        .prefix("session-")
+        .tempdir_in(static_tmp_root()?)
+        .map_err(|e| e.to_string())
+}
+
+/// Process-scoped scratch space for "try before you keep" asset previews.
+/// Lives under `static/tmp/` so previews resolve through the same
+/// project-relative convention as `import_assets`. The held `TempDir` is
+/// removed from disk when the app exits (or sooner, via
+/// `clear_temp_imports`) since dropping it is what deletes the directory.
+struct TempImportScratch(Mutex<TempDir>);
+
+impl TempImportScratch {
+    fn new() -> Result<Self, String> {
+        Ok(Self(Mutex::new(new_scratch_dir()?)))
+    }
+}
+
+fn import_temp_assets_impl(
+    scratch_path: &Path,
+    paths: Vec<String>,
+) -> Result<Vec<ImportOutcome>, String> {
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let project_root = std::env::current_dir().map_err(|e| e.to_string())?;
+
+    let mut outcomes = Vec::new();
+    for source_str in paths {
+        let source_path = PathBuf::from(&source_str);
+        if !source_path.exists() {
+            outcomes.push(ImportOutcome::err(
+                source_str.clone(),
+                ImportErrorKind::NotFound,
+                format!("Asset not found: {}", source_str),
+            ));
+            continue;
+        }
+
+        let file_name = match source_path.file_name() {
+            Some(name) => name,
+            None => {
+                outcomes.push(ImportOutcome::err(
+                    source_str.clone(),
+                    ImportErrorKind::InvalidName,
+                    format!("Invalid asset path: {}", source_str),
+                ));
+                continue;
+            }
+        };
+
+        let destination = unique_destination(scratch_path.join(file_name));
+        if let Err(error) = fs::copy(&source_path, &destination) {
+            outcomes.push(ImportOutcome::err(
+                source_str,
+                ImportErrorKind::CopyFailed,
+                format!("Failed to copy asset: {}", error),
+            ));
+            continue;
+        }
+
+        let relative = destination
+            .strip_prefix(&project_root)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| destination.to_string_lossy().to_string());
+
+        outcomes.push(ImportOutcome::ok(source_str, relative));
+    }
+
+    Ok(outcomes)
+}
+
+#[tauri::command]
+fn import_temp_assets(
+    scratch: tauri::State<TempImportScratch>,
+    paths: Vec<String>,
+) -> Result<Vec<ImportOutcome>, String> {
+    let scratch_dir = scratch.0.lock().map_err(|e| e.to_string())?;
+    import_temp_assets_impl(scratch_dir.path(), paths)
+}
+
+#[tauri::command]
+fn clear_temp_imports(scratch: tauri::State<TempImportScratch>) -> Result<(), String> {
+    let fresh_dir = new_scratch_dir()?;
+    let mut current = scratch.0.lock().map_err(|e| e.to_string())?;
+    *current = fresh_dir;
+    Ok(())
+}
+
+fn commit_temp_import_impl(scratch_path: &Path, temp_path: String) -> Result<String, String> {
+    let project_root = std::env::current_dir().map_err(|e| e.to_string())?;
+    // `temp_path` is the project-relative path import_temp_assets returned
+    // (e.g. `static/tmp/session-xxx/foo.png`), so it must be resolved
+    // against the project root before comparing it to the scratch dir's
+    // absolute path.
+    let temp_file = project_root.join(&temp_path);
+
+    if !temp_file.starts_with(scratch_path) {
+        return Err(format!("Not a temp import path: {}", temp_path));
+    }
+    if !temp_file.exists() {
+        return Err(format!("Temp asset not found: {}", temp_path));
+    }
+
+    let static_dir = project_root.join("static").join("imports");
+    fs::create_dir_all(&static_dir).map_err(|e| e.to_string())?;
+
+    let file_name = temp_file
+        .file_name()
+        .ok_or_else(|| format!("Invalid temp asset path: {}", temp_path))?;
+
+    let digest = digest_file(&temp_file)?;
+    let mut manifest = load_imports_manifest(&static_dir);
+    if let Some((served, _)) = manifest
+        .get(&digest)
+        .and_then(|entry| resolve_cached_import(&project_root, entry))
+    {
+        return Ok(served);
+    }
+
+    let destination = unique_destination(static_dir.join(file_name));
+    move_file(&temp_file, &destination)?;
+
+    let relative = destination
+        .strip_prefix(&project_root)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| destination.to_string_lossy().to_string());
+    let original_size = fs::metadata(&destination).map(|m| m.len()).unwrap_or(0);
+
+    manifest.insert(
+        digest,
+        ImportManifestEntry {
+            path: Some(relative.clone()),
+            original_size,
+            compressed_path: None,
+            compressed_size: None,
+        },
+    );
+    save_imports_manifest(&static_dir, &manifest)?;
+
+    Ok(relative)
+}
+
+#[tauri::command]
+fn commit_temp_import(
+    scratch: tauri::State<TempImportScratch>,
+    temp_path: String,
+) -> Result<String, String> {
+    let scratch_dir = scratch.0.lock().map_err(|e| e.to_string())?;
+    commit_temp_import_impl(scratch_dir.path(), temp_path)
+}
+
+#[tauri::command]
+fn read_imports_manifest() -> Result<ImportsManifest, String> {
+    let project_root = std::env::current_dir().map_err(|e| e.to_string())?;
+    let static_dir = project_root.join("static").join("imports");
+    Ok(load_imports_manifest(&static_dir))
+}
+
+#[tauri::command]
+fn purge_uncompressed_originals() -> Result<Vec<String>, String> {
+    let project_root = std::env::current_dir().map_err(|e| e.to_string())?;
+    let static_dir = project_root.join("static").join("imports");
+    let mut manifest = load_imports_manifest(&static_dir);
+
+    let mut purged = Vec::new();
+    let mut manifest_dirty = false;
+    for entry in manifest.values_mut() {
+        // Only purge when the compressed sibling is actually present on
+        // disk — a manifest claim alone isn't enough. A prior compression
+        // failure or a user manually clearing `static/imports/*.png.br`
+        // would otherwise leave nothing to serve this digest, so skip it.
+        let has_compressed_sibling = entry
+            .compressed_path
+            .as_ref()
+            .is_some_and(|compressed_path| project_root.join(compressed_path).exists());
+        if !has_compressed_sibling {
+            continue;
         }
+        let Some(original_relative) = entry.path.take() else {
+            continue;
+        };
+
+        let original_path = project_root.join(&original_relative);
+        if original_path.exists() {
+            if let Err(error) = fs::remove_file(&original_path) {
+                entry.path = Some(original_relative);
+                return Err(error.to_string());
+            }
+            purged.push(original_relative);
+        }
+        manifest_dirty = true;
+    }
+
+    if manifest_dirty {
+        save_imports_manifest(&static_dir, &manifest)?;
     }
 
-    Ok(imported)
+    Ok(purged)
 }
 
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![read_project_dir, import_assets])
+        .manage(TempImportScratch::new().expect("failed to create temp import scratch dir"))
+        .invoke_handler(tauri::generate_handler![
+            read_project_dir,
+            read_project_dir_tree,
+            import_assets,
+            import_assets_to,
+            import_temp_assets,
+            clear_temp_imports,
+            commit_temp_import,
+            read_imports_manifest,
+            purge_uncompressed_originals
+        ])
         .run(tauri::generate_context!())
         .expect("error while running Slot Studio");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_temp_asset_then_commit_moves_it_into_static_imports() {
+        let project_dir = tempfile::tempdir().expect("create project dir");
+        std::env::set_current_dir(project_dir.path()).expect("enter project dir");
+
+        let source_dir = tempfile::tempdir().expect("create source dir");
+        let source_path = source_dir.path().join("wild.png");
+        fs::write(&source_path, b"reel symbol bytes").expect("write source asset");
+
+        let scratch_dir = new_scratch_dir().expect("create scratch dir");
+
+        let outcomes = import_temp_assets_impl(
+            scratch_dir.path(),
+            vec![source_path.to_string_lossy().to_string()],
+        )
+        .expect("import into scratch");
+        let temp_relative = outcomes[0]
+            .relative_path
+            .clone()
+            .expect("temp import should report a relative path");
+        assert!(temp_relative.starts_with("static/tmp/"));
+
+        let committed_relative = commit_temp_import_impl(scratch_dir.path(), temp_relative.clone())
+            .expect("commit temp import");
+        assert!(committed_relative.starts_with("static/imports/"));
+        assert!(project_dir.path().join(&committed_relative).exists());
+        assert!(!project_dir.path().join(&temp_relative).exists());
+    }
+}